@@ -4,13 +4,17 @@ use std::io::{self, Read, Write};
 use std::net::{TcpListener, TcpStream};
 use std::path::{Path, PathBuf};
 use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+extern crate brotli;
 extern crate flate2;
+use brotli::CompressorWriter;
 use flate2::write::{GzEncoder, ZlibEncoder};
 use flate2::Compression;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum CompressionScheme {
+    Brotli,
     Gzip,
     Zlib,
 }
@@ -18,12 +22,24 @@ enum CompressionScheme {
 struct CompressionUtil;
 
 impl CompressionUtil {
+    // Preference order for tie-breaking when the client accepts several
+    // codings with an equal q-value.
     fn supported_schemes() -> Vec<CompressionScheme> {
-        vec![CompressionScheme::Gzip, CompressionScheme::Zlib]
+        vec![
+            CompressionScheme::Brotli,
+            CompressionScheme::Gzip,
+            CompressionScheme::Zlib,
+        ]
     }
 
     fn compress(data: &[u8], scheme: CompressionScheme) -> io::Result<Vec<u8>> {
         match scheme {
+            CompressionScheme::Brotli => {
+                let mut encoder = CompressorWriter::new(Vec::new(), 4096, 11, 22);
+                encoder.write_all(data)?;
+                encoder.flush()?;
+                Ok(encoder.into_inner())
+            }
             CompressionScheme::Gzip => {
                 let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
                 encoder.write_all(data)?;
@@ -39,21 +55,64 @@ impl CompressionUtil {
 
     fn scheme_to_header(scheme: CompressionScheme) -> &'static str {
         match scheme {
+            CompressionScheme::Brotli => "br",
             CompressionScheme::Gzip => "gzip",
-            CompressionScheme::Zlib => "zlib",
+            // A zlib-wrapped deflate stream is signaled over the wire as
+            // "deflate" — "zlib" is not a registered Content-Encoding token.
+            CompressionScheme::Zlib => "deflate",
         }
     }
 
+    // Splits a single `Accept-Encoding` element into its coding name and
+    // q-value, defaulting to q=1.0 when no `;q=` parameter is present.
+    fn parse_encoding(element: &str) -> Option<(String, f32)> {
+        let mut parts = element.split(';');
+        let coding = parts.next()?.trim().to_lowercase();
+        if coding.is_empty() {
+            return None;
+        }
+
+        let q = parts
+            .find_map(|param| {
+                param
+                    .trim()
+                    .strip_prefix("q=")
+                    .and_then(|v| v.trim().parse::<f32>().ok())
+            })
+            .unwrap_or(1.0);
+
+        Some((coding, q))
+    }
+
     fn negotiate_compression(accept_encoding: &str) -> Option<CompressionScheme> {
-        let encodings: Vec<String> = accept_encoding
+        let encodings: Vec<(String, f32)> = accept_encoding
             .split(',')
-            .map(|s| s.trim().to_lowercase())
+            .filter_map(Self::parse_encoding)
             .collect();
 
-        Self::supported_schemes().into_iter().find(|&scheme| {
-            let scheme_str = Self::scheme_to_header(scheme).to_lowercase();
-            encodings.contains(&scheme_str)
-        })
+        let wildcard_q = encodings
+            .iter()
+            .find(|(coding, _)| coding == "*")
+            .map(|&(_, q)| q);
+
+        // Walk the preference order and keep the first coding whose q-value
+        // is strictly greater than the current best, so ties resolve in
+        // favor of the server's preferred scheme (Brotli > gzip > zlib).
+        Self::supported_schemes()
+            .into_iter()
+            .filter_map(|scheme| {
+                let header = Self::scheme_to_header(scheme);
+                let q = match encodings.iter().find(|(coding, _)| coding == header) {
+                    Some(&(_, q)) => Some(q),
+                    None => wildcard_q,
+                };
+                q.filter(|&q| q > 0.0).map(|q| (scheme, q))
+            })
+            .fold(None, |best, (scheme, q)| match best {
+                Some((_, best_q)) if best_q >= q => best,
+                _ => Some((scheme, q)),
+            })
+            .map(|(scheme, _)| scheme)
     }
 }
 
@@ -67,7 +126,7 @@ struct HttpRequest {
     method: String,
     path: String,
     headers: Vec<(String, String)>,
-    body: Option<String>,
+    body: Option<Vec<u8>>,
 }
 
 struct HttpResponse {
@@ -82,7 +141,10 @@ impl HttpResponse {
         HttpResponse {
             status_code,
             status_message: status_message.to_string(),
-            headers: vec![],
+            // Every response carries a Content-Length by default, even the
+            // many bodyless ones (4xx/5xx, 201, 304...), so a keep-alive
+            // client always knows where the response ends.
+            headers: vec![("Content-Length".to_string(), "0".to_string())],
             body: vec![],
         }
     }
@@ -91,8 +153,7 @@ impl HttpResponse {
         self.body = body;
         self.headers
             .push(("Content-Type".to_string(), content_type.to_string()));
-        self.headers
-            .push(("Content-Length".to_string(), self.body.len().to_string()));
+        self.set_header("Content-Length", &self.body.len().to_string());
         self
     }
 
@@ -101,6 +162,13 @@ impl HttpResponse {
         self
     }
 
+    fn set_header(&mut self, key: &str, value: &str) {
+        match self.headers.iter_mut().find(|(k, _)| k == key) {
+            Some(existing) => existing.1 = value.to_string(),
+            None => self.headers.push((key.to_string(), value.to_string())),
+        }
+    }
+
     fn to_bytes(&self) -> Vec<u8> {
         let status_line = format!("HTTP/1.1 {} {}\r\n", self.status_code, self.status_message);
         let headers = self
@@ -119,11 +187,147 @@ impl HttpResponse {
     }
 }
 
+// Returns the index of the first occurrence of `needle` within `haystack`.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+// Guesses the `Content-Type` for a served file from its extension, falling
+// back to `application/octet-stream` for anything unrecognized.
+fn mime_for_path(path: &Path) -> &'static str {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .as_deref()
+    {
+        Some("html") => "text/html",
+        Some("css") => "text/css",
+        Some("js") => "application/javascript",
+        Some("json") => "application/json",
+        Some("png") => "image/png",
+        Some("txt") => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+// Decodes `%XX` percent-escapes in a request path into raw bytes before any
+// routing decisions are made on it.
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 3 <= bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// A minimal RFC 1123 ("HTTP-date") formatter/parser — the one date format
+// HTTP conditional requests need, implemented without pulling in a
+// date/time crate.
+mod http_date {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTH_NAMES: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    pub fn format(time: SystemTime) -> String {
+        let secs = time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        let days = secs.div_euclid(86400);
+        let time_of_day = secs.rem_euclid(86400);
+        let (year, month, day) = civil_from_days(days);
+        let weekday = (days + 4).rem_euclid(7) as usize;
+
+        format!(
+            "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+            DAY_NAMES[weekday],
+            day,
+            MONTH_NAMES[(month - 1) as usize],
+            year,
+            time_of_day / 3600,
+            (time_of_day % 3600) / 60,
+            time_of_day % 60,
+        )
+    }
+
+    pub fn parse(value: &str) -> Option<SystemTime> {
+        // e.g. "Sun, 06 Nov 1994 08:49:37 GMT"
+        let parts: Vec<&str> = value.split_whitespace().collect();
+        if parts.len() != 6 {
+            return None;
+        }
+
+        let day: i64 = parts[1].parse().ok()?;
+        let month = MONTH_NAMES.iter().position(|&m| m == parts[2])? as i64 + 1;
+        let year: i64 = parts[3].parse().ok()?;
+
+        let mut time_parts = parts[4].split(':');
+        let hour: i64 = time_parts.next()?.parse().ok()?;
+        let minute: i64 = time_parts.next()?.parse().ok()?;
+        let second: i64 = time_parts.next()?.parse().ok()?;
+
+        let secs = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second;
+        if secs < 0 {
+            return None;
+        }
+        Some(UNIX_EPOCH + Duration::from_secs(secs as u64))
+    }
+
+    // Howard Hinnant's civil_from_days/days_from_civil algorithm, valid over
+    // the full range of years representable by `i64` days since the epoch.
+    fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+        let y = if m <= 2 { y - 1 } else { y };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = (m + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    fn civil_from_days(z: i64) -> (i64, i64, i64) {
+        let z = z + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let d = doy - (153 * mp + 2) / 5 + 1;
+        let m = if mp < 10 { mp + 3 } else { mp - 9 };
+        (if m <= 2 { y + 1 } else { y }, m, d)
+    }
+}
+
 struct Router;
 
 impl Router {
-    fn parse_request(raw_request: &str) -> io::Result<HttpRequest> {
-        let mut lines = raw_request.lines();
+    fn parse_request(raw_request: &[u8]) -> io::Result<HttpRequest> {
+        // Only the header block is guaranteed to be text; the body may be
+        // arbitrary binary data, so it stays untouched as raw bytes.
+        let headers_end = find_subslice(raw_request, b"\r\n\r\n")
+            .map(|pos| pos + 4)
+            .unwrap_or(raw_request.len());
+        let header_text = String::from_utf8_lossy(&raw_request[..headers_end]);
+
+        let mut lines = header_text.lines();
         let request_line = lines.next().ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidInput,
@@ -140,20 +344,20 @@ impl Router {
         }
 
         let method = parts[0].to_string();
-        let path = parts[1].to_string();
+        let path = percent_decode(parts[1]);
 
         let mut headers = Vec::new();
-        let mut body = None;
-
         for line in lines.take_while(|l| !l.is_empty()) {
             if let Some((key, value)) = line.split_once(':') {
                 headers.push((key.trim().to_string(), value.trim().to_string()));
             }
         }
 
-        if let Some(body_start) = raw_request.find("\r\n\r\n") {
-            body = Some(raw_request[body_start + 4..].to_string());
-        }
+        let body = if headers_end < raw_request.len() {
+            Some(raw_request[headers_end..].to_vec())
+        } else {
+            None
+        };
 
         Ok(HttpRequest {
             method,
@@ -177,7 +381,7 @@ impl Router {
             (method, path) if path.starts_with("/files/") => {
                 let filename = &path[7..];
                 match method {
-                    "GET" => Self::serve_file(filename, config),
+                    "GET" => Self::serve_file(filename, req, config),
                     "POST" => Self::create_file(filename, req, config),
                     _ => Self::method_not_allowed(),
                 }
@@ -208,23 +412,149 @@ impl Router {
         Self::compress_response(body, "text/plain", compression)
     }
 
-    fn serve_file(filename: &str, config: &ServerConfig) -> HttpResponse {
+    fn serve_file(filename: &str, req: &HttpRequest, config: &ServerConfig) -> HttpResponse {
         let file_path = config.directory.join(filename);
 
-        match fs::read(&file_path) {
-            Ok(content) => {
-                HttpResponse::new(200, "OK").with_body(content, "application/octet-stream")
+        if !Self::is_within_directory(config, &file_path) {
+            return HttpResponse::new(403, "Forbidden");
+        }
+
+        let content = match fs::read(&file_path) {
+            Ok(content) => content,
+            Err(_) => return HttpResponse::new(404, "Not Found"),
+        };
+        let last_modified = fs::metadata(&file_path).and_then(|m| m.modified()).ok();
+
+        if let Some(last_modified) = last_modified {
+            let not_modified = req
+                .headers
+                .iter()
+                .find(|(k, _)| k.to_lowercase() == "if-modified-since")
+                .and_then(|(_, v)| http_date::parse(v))
+                .map(|if_modified_since| !Self::is_modified_since(last_modified, if_modified_since))
+                .unwrap_or(false);
+
+            if not_modified {
+                return HttpResponse::new(304, "Not Modified")
+                    .with_header("Last-Modified", &http_date::format(last_modified));
+            }
+        }
+
+        let total_len = content.len();
+        let content_type = mime_for_path(&file_path);
+
+        let range_header = req
+            .headers
+            .iter()
+            .find(|(k, _)| k.to_lowercase() == "range")
+            .map(|(_, v)| v.as_str());
+
+        let response = match range_header {
+            None => HttpResponse::new(200, "OK")
+                .with_body(content, content_type)
+                .with_header("Accept-Ranges", "bytes"),
+            Some(range_header) => match Self::parse_range(range_header, total_len) {
+                Some((start, end)) => HttpResponse::new(206, "Partial Content")
+                    .with_body(content[start..=end].to_vec(), content_type)
+                    .with_header("Accept-Ranges", "bytes")
+                    .with_header(
+                        "Content-Range",
+                        &format!("bytes {}-{}/{}", start, end, total_len),
+                    ),
+                None => {
+                    return HttpResponse::new(416, "Range Not Satisfiable")
+                        .with_header("Content-Range", &format!("bytes */{}", total_len))
+                }
+            },
+        };
+
+        match last_modified {
+            Some(last_modified) => {
+                response.with_header("Last-Modified", &http_date::format(last_modified))
             }
-            Err(_) => HttpResponse::new(404, "Not Found"),
+            None => response,
+        }
+    }
+
+    // Compares at one-second resolution, matching the precision of an
+    // HTTP-date, so a file is only considered modified if it changed after
+    // the timestamp the client already has cached.
+    fn is_modified_since(last_modified: SystemTime, if_modified_since: SystemTime) -> bool {
+        let to_secs = |t: SystemTime| t.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        to_secs(last_modified) > to_secs(if_modified_since)
+    }
+
+    // Parses a single `Range: bytes=start-end` header value, including the
+    // suffix form `bytes=-N` and the open-ended form `bytes=N-`, into an
+    // inclusive `(start, end)` byte range. Returns `None` if the header is
+    // malformed, spans multiple ranges, or cannot be satisfied for a file of
+    // `total_len` bytes.
+    fn parse_range(range_header: &str, total_len: usize) -> Option<(usize, usize)> {
+        if total_len == 0 {
+            return None;
+        }
+
+        let spec = range_header.strip_prefix("bytes=")?;
+        if spec.contains(',') {
+            return None;
+        }
+        let (start_str, end_str) = spec.split_once('-')?;
+
+        let (start, end) = if start_str.is_empty() {
+            let suffix_len: usize = end_str.parse().ok()?;
+            if suffix_len == 0 {
+                return None;
+            }
+            (total_len.saturating_sub(suffix_len), total_len - 1)
+        } else {
+            let start: usize = start_str.parse().ok()?;
+            let end = match end_str.is_empty() {
+                true => total_len - 1,
+                false => end_str.parse().ok()?,
+            };
+            (start, end)
+        };
+
+        if start > end || end >= total_len {
+            return None;
+        }
+
+        Some((start, end))
+    }
+
+    // Canonicalizes `path` (or, if it doesn't exist yet, its parent) and
+    // checks that it still lives under `config.directory`, so decoded `..`
+    // segments or absolute paths can't be used to escape the served root.
+    fn is_within_directory(config: &ServerConfig, path: &Path) -> bool {
+        let canonical_root = match fs::canonicalize(&config.directory) {
+            Ok(root) => root,
+            Err(_) => return false,
+        };
+
+        let canonical_candidate = if path.exists() {
+            fs::canonicalize(path).ok()
+        } else {
+            path.parent()
+                .and_then(|parent| fs::canonicalize(parent).ok())
+                .map(|parent| parent.join(path.file_name().unwrap_or_default()))
+        };
+
+        match canonical_candidate {
+            Some(candidate) => candidate.starts_with(&canonical_root),
+            None => false,
         }
     }
 
     fn create_file(filename: &str, req: &HttpRequest, config: &ServerConfig) -> HttpResponse {
         let file_path = config.directory.join(filename);
 
+        if !Self::is_within_directory(config, &file_path) {
+            return HttpResponse::new(403, "Forbidden");
+        }
+
         if !file_path.exists() {
             fs::File::create(&file_path)
-                .expect(format!("Error in creating file {}", file_path.display()).as_str());
+                .unwrap_or_else(|_| panic!("Error in creating file {}", file_path.display()));
         }
 
         match req.body.as_ref() {
@@ -267,18 +597,99 @@ impl Router {
 struct ConnectionHandler;
 
 impl ConnectionHandler {
+    // Handles every request sent over one TCP connection, looping for
+    // HTTP/1.1 keep-alive until the client asks to close or disconnects.
     fn handle_client(mut stream: TcpStream, config: ServerConfig) -> io::Result<()> {
-        let mut buffer = [0; 1024];
-        let bytes_read = stream.read(&mut buffer)?;
-        let request_str = String::from_utf8_lossy(&buffer[..bytes_read]);
+        let mut pending = Vec::new();
+        loop {
+            let raw_request = match Self::read_request(&mut stream, &mut pending)? {
+                Some(raw_request) => raw_request,
+                None => return Ok(()),
+            };
+
+            let request = Router::parse_request(&raw_request)?;
+            let keep_alive = Self::should_keep_alive(&request);
+
+            let response = Router::handle_request(&request, &config).with_header(
+                "Connection",
+                if keep_alive { "keep-alive" } else { "close" },
+            );
+
+            stream.write_all(&response.to_bytes())?;
+            stream.flush()?;
+
+            if !keep_alive {
+                return Ok(());
+            }
+        }
+    }
+
+    // Reads one full HTTP request off `stream`: the header block up to the
+    // blank line, then as many more bytes as `Content-Length` declares.
+    // Returns `Ok(None)` if the peer closed the connection before sending
+    // anything, signalling that the keep-alive loop should stop. Bytes read
+    // past the end of this request are left in `pending` for the next call.
+    fn read_request(
+        stream: &mut TcpStream,
+        pending: &mut Vec<u8>,
+    ) -> io::Result<Option<Vec<u8>>> {
+        let mut buffer = std::mem::take(pending);
+        let mut chunk = [0; 1024];
+
+        let headers_end = loop {
+            if let Some(pos) = find_subslice(&buffer, b"\r\n\r\n") {
+                break pos + 4;
+            }
 
-        let request = Router::parse_request(&request_str)?;
-        let response = Router::handle_request(&request, &config);
+            let bytes_read = stream.read(&mut chunk)?;
+            if bytes_read == 0 {
+                return if buffer.is_empty() {
+                    Ok(None)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "Connection closed mid-request",
+                    ))
+                };
+            }
+            buffer.extend_from_slice(&chunk[..bytes_read]);
+        };
+
+        let content_length = Self::content_length(&buffer[..headers_end]);
+        while buffer.len() < headers_end + content_length {
+            let bytes_read = stream.read(&mut chunk)?;
+            if bytes_read == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Connection closed before full body was received",
+                ));
+            }
+            buffer.extend_from_slice(&chunk[..bytes_read]);
+        }
 
-        stream.write_all(&response.to_bytes())?;
-        stream.flush()?;
+        *pending = buffer.split_off(headers_end + content_length);
+        Ok(Some(buffer))
+    }
 
-        Ok(())
+    fn content_length(header_bytes: &[u8]) -> usize {
+        String::from_utf8_lossy(header_bytes)
+            .lines()
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case("content-length")
+                    .then(|| value.trim().parse::<usize>().ok())
+                    .flatten()
+            })
+            .unwrap_or(0)
+    }
+
+    fn should_keep_alive(req: &HttpRequest) -> bool {
+        req.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case("connection"))
+            .map(|(_, v)| !v.eq_ignore_ascii_case("close"))
+            .unwrap_or(true)
     }
 }
 
@@ -335,3 +746,97 @@ fn main() -> io::Result<()> {
     let server = HttpServer::new(4221, directory);
     server.run()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_compression_breaks_ties_by_server_preference() {
+        // Brotli and gzip tie at q=0.5; Brotli wins the server's preference order.
+        assert_eq!(
+            CompressionUtil::negotiate_compression("gzip;q=0.5, br;q=0.5"),
+            Some(CompressionScheme::Brotli)
+        );
+    }
+
+    #[test]
+    fn negotiate_compression_explicit_refusal_beats_wildcard() {
+        // An explicit q=0 excludes Brotli even though the wildcard would otherwise allow it.
+        assert_eq!(
+            CompressionUtil::negotiate_compression("br;q=0, *"),
+            Some(CompressionScheme::Gzip)
+        );
+    }
+
+    #[test]
+    fn negotiate_compression_bare_wildcard_defaults_to_q1() {
+        // A bare "*" accepts every supported coding at q=1.0; Brotli wins on preference.
+        assert_eq!(
+            CompressionUtil::negotiate_compression("*"),
+            Some(CompressionScheme::Brotli)
+        );
+    }
+
+    #[test]
+    fn parse_range_suffix_form() {
+        assert_eq!(Router::parse_range("bytes=-10", 100), Some((90, 99)));
+    }
+
+    #[test]
+    fn parse_range_open_ended_form() {
+        assert_eq!(Router::parse_range("bytes=50-", 100), Some((50, 99)));
+    }
+
+    #[test]
+    fn parse_range_rejects_start_greater_than_end() {
+        assert_eq!(Router::parse_range("bytes=50-10", 100), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_end_beyond_total_len() {
+        assert_eq!(Router::parse_range("bytes=0-100", 100), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_on_zero_length_file() {
+        assert_eq!(Router::parse_range("bytes=0-0", 0), None);
+    }
+
+    #[test]
+    fn parse_range_rejects_multi_range_requests() {
+        assert_eq!(Router::parse_range("bytes=0-10,20-30", 100), None);
+    }
+
+    #[test]
+    fn http_date_formats_the_unix_epoch() {
+        assert_eq!(
+            http_date::format(UNIX_EPOCH),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn http_date_parses_a_known_value() {
+        assert_eq!(
+            http_date::parse("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(UNIX_EPOCH + std::time::Duration::from_secs(784111777))
+        );
+    }
+
+    #[test]
+    fn http_date_round_trips_through_format_and_parse() {
+        let samples = [
+            UNIX_EPOCH,
+            UNIX_EPOCH + std::time::Duration::from_secs(86400), // 1970-01-02
+            UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000), // 2023-11-14
+            UNIX_EPOCH + std::time::Duration::from_secs(4_102_444_800), // 2100-01-01
+        ];
+
+        for sample in samples {
+            let formatted = http_date::format(sample);
+            let parsed = http_date::parse(&formatted).expect("should parse its own output");
+            assert_eq!(parsed, sample, "round-trip mismatch for {}", formatted);
+        }
+    }
+}